@@ -1,95 +1,312 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::io::{self, BufRead};
 
-use difflib::get_close_matches;
+mod morphology;
+mod server;
+mod similarity;
+mod thesaurus;
+mod wiktionary;
 
-const JSON_DATA: &str = include_str!("data/english_english.json");
+use morphology::Morphology;
+use thesaurus::Thesaurus;
 
-type Definitions = Vec<String>;
+const THESAURUS_DATA: &str = include_str!("data/thesaurus_english.jsonl");
+const FORMS_DATA: &str = include_str!("data/forms_english.jsonl");
 
+/// Bundled `(language code, JSON data)` pairs, one per supported language.
+const BUNDLED_DICTIONARIES: &[(&str, &str)] = &[
+    ("en", include_str!("data/english_english.json")),
+    ("fr", include_str!("data/english_french.json")),
+];
+
+const DEFAULT_LANG: &str = "en";
+
+pub(crate) type Definitions = Vec<String>;
+
+/// Tracks the user's active language selection for the REPL's `:lang`
+/// command, independently of which dictionary data is loaded.
 #[derive(Debug)]
-enum DictionaryError {
+struct State {
+    lang: String,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State {
+            lang: DEFAULT_LANG.to_string(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum DictionaryError {
     NotFound,
-    IncorrectWord(String),
-    UnknownInput,
+    InvalidData { key: String, reason: String },
 }
 
 impl std::fmt::Display for DictionaryError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             DictionaryError::NotFound => write!(f, "The word doesn't exist."),
-            DictionaryError::IncorrectWord(correct_word) => {
-                write!(f, "Did you mean {} instead?", correct_word)
+            DictionaryError::InvalidData { key, reason } => {
+                write!(f, "Invalid data for '{}': {}", key, reason)
             }
-            DictionaryError::UnknownInput => write!(f, "We didn't understand your input."),
         }
     }
 }
 
-#[derive(Debug)]
-enum UserResponse {
-    Yes,
-    No,
-    Unknown,
+impl std::error::Error for DictionaryError {}
+
+fn print_synonyms(synonyms: &[String]) {
+    println!("Synonyms:");
+    for synonym in synonyms {
+        println!("- {}", synonym);
+    }
+}
+
+fn print_definitions(definitions: &[String]) {
+    println!("Definitions:");
+    for defn in definitions {
+        println!("- {}", defn);
+    }
 }
 
-impl UserResponse {
-    fn from_str(input: &str) -> Self {
-        match input.trim().to_lowercase().as_str() {
-            "y" | "yes" => UserResponse::Yes,
-            "n" | "no" => UserResponse::No,
-            _ => UserResponse::Unknown,
+fn print_thesaurus_entry(thesaurus: &Thesaurus, word: &str) {
+    if let Ok(synonyms) = thesaurus.synonyms(word) {
+        print_synonyms(&synonyms);
+    }
+    if let Ok(antonyms) = thesaurus.antonyms(word) {
+        if !antonyms.is_empty() {
+            println!("Antonyms:");
+            for antonym in antonyms {
+                println!("- {}", antonym);
+            }
         }
     }
 }
 
+/// Prints a numbered menu of `(word, similarity)` candidates and reads the
+/// user's selection, returning the chosen word or `None` if they typed
+/// nothing or an out-of-range number.
+fn prompt_selection(candidates: &[(String, f64)]) -> Option<String> {
+    println!("Did you mean one of these?");
+    for (i, (candidate, score)) in candidates.iter().enumerate() {
+        println!("  {}. {} ({:.0}% match)", i + 1, candidate, score * 100.0);
+    }
+    println!("Enter a number to pick one, or press enter to skip:");
+
+    let mut input = String::new();
+    io::stdin().lock().read_line(&mut input).ok()?;
+    let choice: usize = input.trim().parse().ok()?;
+    let index = choice.checked_sub(1)?;
+    candidates.get(index).map(|(word, _)| word.clone())
+}
+
+/// A single definition tagged with the language it was sourced from.
+#[derive(Debug, Clone)]
+struct LocalizedDefinition {
+    lang: String,
+    text: String,
+}
+
 #[derive(Debug)]
 struct Dictionary {
-    data: HashMap<String, Definitions>,
+    data: HashMap<String, Vec<LocalizedDefinition>>,
+    morphology: Morphology,
+    lang: String,
+    online: bool,
 }
 
 impl Dictionary {
-    fn from_json(json_data: &str) -> Result<Self, serde_json::Error> {
-        let data: HashMap<String, Definitions> = serde_json::from_str(json_data)?;
-        Ok(Dictionary { data })
+    /// Maximum byte length of a headword. Generous default so embedders
+    /// bundling large merged datasets get predictable memory behavior.
+    pub(crate) const MAX_KEY_LEN: usize = 128;
+    /// Maximum byte length of a single definition string.
+    pub(crate) const MAX_DEFINITION_LEN: usize = 4096;
+    /// Maximum total number of (word, definition) entries across all
+    /// bundled languages.
+    pub(crate) const MAX_ENTRIES: usize = 200_000;
+
+    /// Loads and merges every bundled language file, preferring `lang`
+    /// when a word has definitions in more than one language. Rejects
+    /// with `DictionaryError::InvalidData` instead of silently loading a
+    /// source file that violates the size limits above.
+    fn load(lang: &str) -> Result<Self, DictionaryError> {
+        let data = Self::merge_sources(BUNDLED_DICTIONARIES)?;
+
+        let morphology =
+            Morphology::from_jsonl(FORMS_DATA).map_err(|err| DictionaryError::InvalidData {
+                key: "forms".to_string(),
+                reason: err.to_string(),
+            })?;
+
+        Ok(Dictionary {
+            data,
+            morphology,
+            lang: lang.to_string(),
+            online: false,
+        })
     }
 
-    fn lookup(&self, word: &str) -> Result<Definitions, DictionaryError> {
-        let word = word.trim().to_lowercase();
-        match self.data.get(&word) {
-            Some(defns) => Ok(defns.clone()),
-            None => {
-                let choices = self.data.keys().map(|key| key.as_str()).collect();
-                match get_close_matches(&word, choices, 1, 0.8).first() {
-                    Some(close_word) => match self.confirm_word(close_word)? {
-                        UserResponse::Yes => self.lookup(close_word),
-                        UserResponse::No => Err(DictionaryError::NotFound),
-                        UserResponse::Unknown => Err(DictionaryError::UnknownInput),
-                    },
-                    None => Err(DictionaryError::NotFound),
+    /// Merges `(language code, JSON data)` sources into a single word ->
+    /// localized-definitions map, enforcing `MAX_KEY_LEN`,
+    /// `MAX_DEFINITION_LEN`, and `MAX_ENTRIES`. Factored out of `load` so
+    /// the limit checks can be exercised directly against small,
+    /// synthetic sources in tests.
+    fn merge_sources(
+        sources: &[(&str, &str)],
+    ) -> Result<HashMap<String, Vec<LocalizedDefinition>>, DictionaryError> {
+        let mut data: HashMap<String, Vec<LocalizedDefinition>> = HashMap::new();
+        let mut entry_count = 0usize;
+
+        for (file_lang, json_data) in sources {
+            let entries: HashMap<String, Definitions> =
+                serde_json::from_str(json_data).map_err(|err| DictionaryError::InvalidData {
+                    key: format!("{} dictionary", file_lang),
+                    reason: err.to_string(),
+                })?;
+
+            for (word, defns) in entries {
+                if word.len() > Self::MAX_KEY_LEN {
+                    return Err(DictionaryError::InvalidData {
+                        key: word,
+                        reason: format!("key exceeds {} bytes", Self::MAX_KEY_LEN),
+                    });
+                }
+
+                let localized = data.entry(word.clone()).or_default();
+                for text in defns {
+                    if text.len() > Self::MAX_DEFINITION_LEN {
+                        return Err(DictionaryError::InvalidData {
+                            key: word,
+                            reason: format!("definition exceeds {} bytes", Self::MAX_DEFINITION_LEN),
+                        });
+                    }
+
+                    entry_count += 1;
+                    if entry_count > Self::MAX_ENTRIES {
+                        return Err(DictionaryError::InvalidData {
+                            key: word,
+                            reason: format!("dictionary exceeds {} entries", Self::MAX_ENTRIES),
+                        });
+                    }
+
+                    localized.push(LocalizedDefinition {
+                        lang: (*file_lang).to_string(),
+                        text,
+                    });
                 }
             }
         }
+
+        Ok(data)
     }
 
-    fn confirm_word(&self, word: &str) -> Result<UserResponse, DictionaryError> {
-        let mut input = String::new();
-        println!("Did you mean {}? (Y/N)", word);
-        io::stdin().lock().read_line(&mut input).unwrap();
+    /// Enables the Wiktionary fallback for words missing from the offline
+    /// data file. Off by default so the JSON path stays the default.
+    fn with_online(mut self, online: bool) -> Self {
+        self.online = online;
+        self
+    }
+
+    /// Switches the preferred language for subsequent lookups without
+    /// reloading the bundled data.
+    fn set_lang(&mut self, lang: &str) {
+        self.lang = lang.to_string();
+    }
 
-        match UserResponse::from_str(&input) {
-            UserResponse::Yes | UserResponse::No => Ok(UserResponse::from_str(&input)),
-            UserResponse::Unknown => Err(DictionaryError::UnknownInput),
+    /// Looks up a word without any interactive prompting. On a direct
+    /// miss, first checks whether `word` is a known inflected form (e.g.
+    /// "running") and resolves to its lemma before giving up; a miss
+    /// there always yields `DictionaryError::NotFound`, leaving it to the
+    /// caller to offer alternatives via `suggest` however it sees fit.
+    pub(crate) fn lookup(&self, word: &str) -> Result<Definitions, DictionaryError> {
+        let word = word.trim().to_lowercase();
+        self.lookup_resolved(&word, &mut HashSet::new())
+    }
+
+    /// Inflection-aware lookup helper. `seen` tracks every form already
+    /// visited on this call chain so a cyclic or self-referential row in
+    /// the forms table (e.g. "foo" resolving to "bar" and back) ends the
+    /// walk with a `NotFound` instead of recursing forever.
+    fn lookup_resolved(
+        &self,
+        word: &str,
+        seen: &mut HashSet<String>,
+    ) -> Result<Definitions, DictionaryError> {
+        if let Some(localized) = self.data.get(word) {
+            let preferred: Definitions = localized
+                .iter()
+                .filter(|defn| defn.lang == self.lang)
+                .map(|defn| defn.text.clone())
+                .collect();
+
+            if !preferred.is_empty() {
+                return Ok(preferred);
+            }
+
+            // The requested language has no entry for this word;
+            // show all entries, tagged by language, instead.
+            return Ok(localized
+                .iter()
+                .map(|defn| format!("[{}] {}", defn.lang, defn.text))
+                .collect());
+        }
+
+        if !seen.insert(word.to_string()) {
+            return Err(DictionaryError::NotFound);
+        }
+
+        if let Some((lemma, note)) = self.morphology.resolve(word) {
+            if let Ok(mut defns) = self.lookup_resolved(lemma, seen) {
+                defns.insert(0, note);
+                return Ok(defns);
+            }
+        }
+
+        Err(DictionaryError::NotFound)
+    }
+
+    /// Returns up to `limit` closest words to `word`, ranked by edit
+    /// distance and paired with their similarity score, for presenting as
+    /// a "Did you mean...?" menu.
+    pub(crate) fn suggest(&self, word: &str, limit: usize) -> Vec<(String, f64)> {
+        let word = word.trim().to_lowercase();
+        similarity::suggest(&word, self.data.keys().map(|key| key.as_str()), limit)
+    }
+
+    /// Queries Wiktionary directly, bypassing the offline data and the
+    /// morphology table. This is a blocking network call, so callers
+    /// should only reach for it once `lookup` has missed *and* the local
+    /// close-match correction flow (`suggest` / interactive confirm) has
+    /// also come up empty. Always fails with `DictionaryError::NotFound`
+    /// when the `--online` flag was not passed.
+    pub(crate) fn lookup_online(&self, word: &str) -> Result<Definitions, DictionaryError> {
+        if !self.online {
+            return Err(DictionaryError::NotFound);
         }
+        let word = word.trim().to_lowercase();
+        wiktionary::fetch_definitions(&word).map_err(|_| DictionaryError::NotFound)
     }
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let dictionary = Dictionary::from_json(JSON_DATA)?;
+    let args: Vec<String> = std::env::args().collect();
+    let online = args.iter().any(|arg| arg == "--online");
+    let mut state = State::default();
+    let mut dictionary = Dictionary::load(&state.lang)?.with_online(online);
+
+    if args.iter().any(|arg| arg == "serve") {
+        return server::run(dictionary);
+    }
+
+    let thesaurus = Thesaurus::from_jsonl(THESAURUS_DATA)?;
 
     loop {
         let mut input = String::new();
-        println!("Enter a word to look up (or 'exit' to quit):");
+        println!(
+            "Enter a word to look up, 'thesaurus <word>' for synonyms, ':lang <code>' to switch language, or 'exit' to quit:"
+        );
         io::stdin().read_line(&mut input)?;
 
         let input = input.trim();
@@ -97,38 +314,110 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             break;
         }
 
-        match dictionary.lookup(&input) {
-            Ok(defns) => {
-                println!("Definitions:");
-                for defn in defns {
-                    println!("- {}", defn);
+        if let Some(lang) = input.strip_prefix(":lang ") {
+            let lang = lang.trim();
+            state.lang = lang.to_string();
+            dictionary.set_lang(lang);
+            println!("Language switched to '{}'.", lang);
+            continue;
+        }
+
+        if let Some(word) = input.strip_prefix("thesaurus ") {
+            match thesaurus.synonyms(word) {
+                Ok(_) => print_thesaurus_entry(&thesaurus, word),
+                Err(DictionaryError::NotFound) => {
+                    let candidates = thesaurus.suggest(word, 5);
+                    match prompt_selection(&candidates) {
+                        Some(chosen) => print_thesaurus_entry(&thesaurus, &chosen),
+                        None => println!("{}", DictionaryError::NotFound),
+                    }
                 }
+                Err(err) => println!("{}", err),
             }
+            continue;
+        }
+
+        match dictionary.lookup(input) {
+            Ok(defns) => print_definitions(&defns),
             Err(DictionaryError::NotFound) => {
-                println!(
-                    "Sorry, the word '{}' was not found in the dictionary.",
-                    input
-                );
-            }
-            Err(DictionaryError::IncorrectWord(correct_word)) => {
-                println!(
-                    "{}",
-                    DictionaryError::IncorrectWord(correct_word.to_owned())
-                );
-                match dictionary.lookup(&correct_word) {
-                    Ok(defns) => {
-                        println!("Definitions:");
-                        for defn in defns {
-                            println!("- {}", defn);
+                let candidates = dictionary.suggest(input, 5);
+                match prompt_selection(&candidates) {
+                    Some(chosen) => {
+                        if let Ok(defns) = dictionary.lookup(&chosen) {
+                            print_definitions(&defns);
                         }
                     }
-                    Err(_) => (),
+                    // Local correction found nothing worth picking; only
+                    // now is it worth paying for a live Wiktionary call.
+                    None => match dictionary.lookup_online(input) {
+                        Ok(defns) => print_definitions(&defns),
+                        Err(_) => println!(
+                            "Sorry, the word '{}' was not found in the dictionary.",
+                            input
+                        ),
+                    },
                 }
             }
-            Err(DictionaryError::UnknownInput) => {
-                println!("{}", DictionaryError::UnknownInput);
-            }
+            Err(err) => println!("{}", err),
         }
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_sources_rejects_key_over_max_len() {
+        let long_key = "a".repeat(Dictionary::MAX_KEY_LEN + 1);
+        let json =
+            serde_json::to_string(&HashMap::from([(long_key, vec!["def".to_string()])])).unwrap();
+
+        let err = Dictionary::merge_sources(&[("en", &json)]).unwrap_err();
+        assert!(matches!(err, DictionaryError::InvalidData { reason, .. } if reason.contains("key exceeds")));
+    }
+
+    #[test]
+    fn merge_sources_rejects_definition_over_max_len() {
+        let long_def = "a".repeat(Dictionary::MAX_DEFINITION_LEN + 1);
+        let json =
+            serde_json::to_string(&HashMap::from([("word".to_string(), vec![long_def])]))
+                .unwrap();
+
+        let err = Dictionary::merge_sources(&[("en", &json)]).unwrap_err();
+        assert!(matches!(err, DictionaryError::InvalidData { reason, .. } if reason.contains("definition exceeds")));
+    }
+
+    #[test]
+    fn merge_sources_rejects_entry_count_over_max_entries() {
+        let mut entries: HashMap<String, Definitions> = HashMap::new();
+        for i in 0..=Dictionary::MAX_ENTRIES {
+            entries.insert(format!("w{}", i), vec!["d".to_string()]);
+        }
+        let json = serde_json::to_string(&entries).unwrap();
+
+        let err = Dictionary::merge_sources(&[("en", &json)]).unwrap_err();
+        assert!(matches!(err, DictionaryError::InvalidData { reason, .. } if reason.contains("exceeds") && reason.contains("entries")));
+    }
+
+    #[test]
+    fn lookup_terminates_on_cyclic_morphology_rows() {
+        let morphology = Morphology::from_jsonl(
+            "{\"form\": \"a\", \"lemma\": \"b\", \"tags\": []}\n\
+             {\"form\": \"b\", \"lemma\": \"a\", \"tags\": []}\n",
+        )
+        .unwrap();
+        let dictionary = Dictionary {
+            data: HashMap::new(),
+            morphology,
+            lang: DEFAULT_LANG.to_string(),
+            online: false,
+        };
+
+        assert!(matches!(
+            dictionary.lookup("a"),
+            Err(DictionaryError::NotFound)
+        ));
+    }
+}
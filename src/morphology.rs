@@ -0,0 +1,56 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct FormEntry {
+    lemma: String,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+/// Maps inflected word forms (plurals, verb tenses, comparatives, ...) to
+/// their base lemma, so `Dictionary::lookup` can resolve "running" to
+/// "run" before falling back to fuzzy suggestions.
+#[derive(Debug)]
+pub(crate) struct Morphology {
+    forms: HashMap<String, FormEntry>,
+}
+
+impl Morphology {
+    pub(crate) fn from_jsonl(jsonl_data: &str) -> Result<Self, serde_json::Error> {
+        let mut forms = HashMap::new();
+        for line in jsonl_data.lines().filter(|line| !line.trim().is_empty()) {
+            #[derive(Deserialize)]
+            struct Row {
+                form: String,
+                lemma: String,
+                #[serde(default)]
+                tags: Vec<String>,
+            }
+            let row: Row = serde_json::from_str(line)?;
+            forms.insert(
+                row.form,
+                FormEntry {
+                    lemma: row.lemma,
+                    tags: row.tags,
+                },
+            );
+        }
+        Ok(Morphology { forms })
+    }
+
+    /// Returns the lemma and a note describing the inflection (e.g.
+    /// `"running — present participle of run"`), if `word` is a known
+    /// inflected form.
+    pub(crate) fn resolve(&self, word: &str) -> Option<(&str, String)> {
+        let entry = self.forms.get(word)?;
+        let tag = entry.tags.join(", ");
+        let note = if tag.is_empty() {
+            format!("{} — form of {}", word, entry.lemma)
+        } else {
+            format!("{} — {} of {}", word, tag, entry.lemma)
+        };
+        Some((&entry.lemma, note))
+    }
+}
@@ -0,0 +1,111 @@
+use serde::Serialize;
+use tiny_http::{Header, Response, Server};
+
+use crate::Dictionary;
+
+const ADDRESS: &str = "127.0.0.1:8080";
+
+#[derive(Serialize)]
+struct DefineResponse {
+    word: String,
+    definitions: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct Suggestion {
+    word: String,
+    score: f64,
+}
+
+#[derive(Serialize)]
+struct SuggestResponse {
+    word: String,
+    suggestions: Vec<Suggestion>,
+}
+
+#[derive(Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+/// Runs the blocking HTTP JSON server, handling one request at a time
+/// against a single shared `Dictionary`:
+/// - `GET /define/{word}` returns its definitions or 404.
+/// - `GET /suggest/{word}` returns ranked close spelling matches.
+pub(crate) fn run(dictionary: Dictionary) -> Result<(), Box<dyn std::error::Error>> {
+    let server = Server::http(ADDRESS).map_err(|err| err.to_string())?;
+    println!("Serving dictionary on http://{}", ADDRESS);
+
+    for request in server.incoming_requests() {
+        let response = handle(&dictionary, request.url());
+        let _ = request.respond(response);
+    }
+
+    Ok(())
+}
+
+fn handle(dictionary: &Dictionary, url: &str) -> Response<std::io::Cursor<Vec<u8>>> {
+    if let Some(word) = url.strip_prefix("/define/") {
+        if let Ok(definitions) = dictionary.lookup(word) {
+            return json_response(
+                200,
+                &DefineResponse {
+                    word: word.to_string(),
+                    definitions,
+                },
+            );
+        }
+
+        // Only worth a live Wiktionary round-trip once a local close
+        // match also comes up empty, so typo'd requests don't block the
+        // single-threaded server on a network call.
+        let candidates = dictionary.suggest(word, 5);
+        if candidates.is_empty() {
+            if let Ok(definitions) = dictionary.lookup_online(word) {
+                return json_response(
+                    200,
+                    &DefineResponse {
+                        word: word.to_string(),
+                        definitions,
+                    },
+                );
+            }
+        }
+
+        return json_response(
+            404,
+            &ErrorResponse {
+                error: format!("'{}' was not found in the dictionary", word),
+            },
+        );
+    }
+
+    if let Some(word) = url.strip_prefix("/suggest/") {
+        let suggestions = dictionary
+            .suggest(word, 5)
+            .into_iter()
+            .map(|(word, score)| Suggestion { word, score })
+            .collect();
+        return json_response(
+            200,
+            &SuggestResponse {
+                word: word.to_string(),
+                suggestions,
+            },
+        );
+    }
+
+    json_response(
+        404,
+        &ErrorResponse {
+            error: "unknown route".to_string(),
+        },
+    )
+}
+
+fn json_response<T: Serialize>(status: u16, body: &T) -> Response<std::io::Cursor<Vec<u8>>> {
+    let bytes = serde_json::to_vec(body).unwrap_or_default();
+    Response::from_data(bytes)
+        .with_status_code(status)
+        .with_header(Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..]).unwrap())
+}
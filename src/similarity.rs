@@ -0,0 +1,104 @@
+/// Ranks `candidates` by Levenshtein edit distance to `word` and returns
+/// the top `limit` as `(candidate, similarity)` pairs, smallest distance
+/// first. Candidates with equal distance keep their input order, so the
+/// returned similarities are not necessarily in descending order.
+///
+/// Similarity is `1.0 - distance / max(len_a, len_b)` where `distance` is
+/// the Levenshtein edit distance. Candidates whose length alone puts them
+/// further away than the current worst kept match are skipped without
+/// running the DP, since no edit distance can be smaller than the
+/// difference in length.
+pub(crate) fn suggest<'a>(
+    word: &str,
+    candidates: impl Iterator<Item = &'a str>,
+    limit: usize,
+) -> Vec<(String, f64)> {
+    let mut best: Vec<(String, usize, usize)> = Vec::new();
+    let mut worst_kept_distance = usize::MAX;
+
+    for candidate in candidates {
+        let len_diff = word.chars().count().abs_diff(candidate.chars().count());
+        if best.len() >= limit && len_diff > worst_kept_distance {
+            continue;
+        }
+
+        let distance = levenshtein_distance(word, candidate);
+        if best.len() >= limit && distance >= worst_kept_distance {
+            continue;
+        }
+
+        let max_len = word.chars().count().max(candidate.chars().count());
+        best.push((candidate.to_string(), distance, max_len));
+        best.sort_by_key(|(_, distance, _)| *distance);
+        best.truncate(limit);
+        worst_kept_distance = best.last().map_or(usize::MAX, |(_, distance, _)| *distance);
+    }
+
+    best.into_iter()
+        .map(|(candidate, distance, max_len)| {
+            let similarity = if max_len == 0 {
+                1.0
+            } else {
+                1.0 - distance as f64 / max_len as f64
+            };
+            (candidate, similarity)
+        })
+        .collect()
+}
+
+/// Levenshtein edit distance computed with two rolling rows of length
+/// `m + 1`, where `m` is the length of the shorter word.
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let (shorter, longer) = if a.len() <= b.len() { (&a, &b) } else { (&b, &a) };
+
+    let mut previous_row: Vec<usize> = (0..=shorter.len()).collect();
+    let mut current_row = vec![0; shorter.len() + 1];
+
+    for (i, &long_ch) in longer.iter().enumerate() {
+        current_row[0] = i + 1;
+        for (j, &short_ch) in shorter.iter().enumerate() {
+            let cost = if long_ch == short_ch { 0 } else { 1 };
+            current_row[j + 1] = (previous_row[j] + cost)
+                .min(previous_row[j + 1] + 1)
+                .min(current_row[j] + 1);
+        }
+        std::mem::swap(&mut previous_row, &mut current_row);
+    }
+
+    previous_row[shorter.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_strings_have_zero_distance_and_full_similarity() {
+        assert_eq!(levenshtein_distance("mouse", "mouse"), 0);
+
+        let results = suggest("mouse", vec!["mouse", "house"].into_iter(), 5);
+        assert_eq!(results[0], ("mouse".to_string(), 1.0));
+    }
+
+    #[test]
+    fn empty_string_distance_is_the_other_strings_length() {
+        assert_eq!(levenshtein_distance("", "mouse"), 5);
+        assert_eq!(levenshtein_distance("mouse", ""), 5);
+        assert_eq!(levenshtein_distance("", ""), 0);
+
+        let results = suggest("", vec!["cat"].into_iter(), 5);
+        assert_eq!(results, vec![("cat".to_string(), 1.0 - 3.0 / 3.0)]);
+    }
+
+    #[test]
+    fn length_diff_early_reject_keeps_the_closer_candidates() {
+        let candidates = vec!["cats", "cathedral", "bats"];
+        let results = suggest("cats", candidates.into_iter(), 1);
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, "cats");
+        assert_eq!(results[0].1, 1.0);
+    }
+}
@@ -0,0 +1,65 @@
+use std::collections::{HashMap, HashSet};
+
+use serde::Deserialize;
+
+use crate::{similarity, DictionaryError};
+
+#[derive(Debug, Deserialize)]
+struct ThesaurusEntry {
+    word: String,
+    #[serde(default)]
+    synonyms: Vec<String>,
+    #[serde(default)]
+    antonyms: Vec<String>,
+}
+
+/// Companion to `Dictionary` that looks up synonyms and antonyms for a
+/// word from a JSONL data file, one entry per line.
+#[derive(Debug)]
+pub(crate) struct Thesaurus {
+    data: HashMap<String, ThesaurusEntry>,
+}
+
+impl Thesaurus {
+    pub(crate) fn from_jsonl(jsonl_data: &str) -> Result<Self, serde_json::Error> {
+        let mut data = HashMap::new();
+        for line in jsonl_data.lines().filter(|line| !line.trim().is_empty()) {
+            let entry: ThesaurusEntry = serde_json::from_str(line)?;
+            data.insert(entry.word.clone(), entry);
+        }
+        Ok(Thesaurus { data })
+    }
+
+    /// Looks up a word's synonyms without any interactive prompting; a
+    /// miss yields `DictionaryError::NotFound`, mirroring `Dictionary::lookup`.
+    pub(crate) fn synonyms(&self, word: &str) -> Result<Vec<String>, DictionaryError> {
+        let word = word.trim().to_lowercase();
+        match self.data.get(&word) {
+            Some(entry) => {
+                let unique: HashSet<String> = entry.synonyms.iter().cloned().collect();
+                Ok(unique.into_iter().collect())
+            }
+            None => Err(DictionaryError::NotFound),
+        }
+    }
+
+    /// Looks up a word's antonyms, mirroring `synonyms`.
+    pub(crate) fn antonyms(&self, word: &str) -> Result<Vec<String>, DictionaryError> {
+        let word = word.trim().to_lowercase();
+        match self.data.get(&word) {
+            Some(entry) => {
+                let unique: HashSet<String> = entry.antonyms.iter().cloned().collect();
+                Ok(unique.into_iter().collect())
+            }
+            None => Err(DictionaryError::NotFound),
+        }
+    }
+
+    /// Returns up to `limit` closest words to `word`, ranked by edit
+    /// distance and paired with their similarity score, for presenting as
+    /// a "Did you mean...?" menu.
+    pub(crate) fn suggest(&self, word: &str, limit: usize) -> Vec<(String, f64)> {
+        let word = word.trim().to_lowercase();
+        similarity::suggest(&word, self.data.keys().map(|key| key.as_str()), limit)
+    }
+}
@@ -0,0 +1,211 @@
+use serde_json::Value;
+
+use crate::Definitions;
+
+const API_BASE: &str = "https://en.wiktionary.org/w/api.php";
+
+/// Fetches a word's English definitions from the Wiktionary API and
+/// renders its wikitext down to plain prose.
+///
+/// This issues a blocking network request, so callers should only reach
+/// it once the offline `Dictionary` lookup and close-match correction
+/// have both failed.
+pub(crate) fn fetch_definitions(word: &str) -> Result<Definitions, String> {
+    let url = format!(
+        "{}?action=parse&format=json&prop=wikitext&page={}",
+        API_BASE,
+        percent_encode(word)
+    );
+    let body = ureq::get(&url)
+        .call()
+        .map_err(|err| err.to_string())?
+        .into_string()
+        .map_err(|err| err.to_string())?;
+
+    let json: Value = serde_json::from_str(&body).map_err(|err| err.to_string())?;
+    let wikitext = json["parse"]["wikitext"]["*"]
+        .as_str()
+        .ok_or_else(|| format!("no wikitext found for '{}'", word))?;
+
+    let definitions = parse_english_definitions(wikitext);
+    if definitions.is_empty() {
+        return Err(format!("no English section found for '{}'", word));
+    }
+    Ok(definitions)
+}
+
+/// Walks the raw wikitext of a Wiktionary page, extracts the `==English==`
+/// section, and pulls out its numbered sense lines (`# ...`), stripping
+/// `{{...}}` templates and `[[link|text]]` markup down to plain prose.
+///
+/// English entries nest their senses under part-of-speech subheadings
+/// (`===Noun===`, `====Etymology====`, ...), so only a genuine next
+/// level-2 heading ends the section — a level-3+ subheading does not.
+/// Quotation (`#:`) and citation (`#*`) sub-bullets are skipped; only
+/// top-level `#` sense lines are extracted.
+fn parse_english_definitions(wikitext: &str) -> Definitions {
+    let mut in_english_section = false;
+    let mut definitions = Vec::new();
+
+    for line in wikitext.lines() {
+        let trimmed = line.trim();
+
+        if trimmed == "==English==" {
+            in_english_section = true;
+            continue;
+        }
+        if in_english_section && is_level2_heading(trimmed) {
+            break;
+        }
+        if !in_english_section {
+            continue;
+        }
+
+        if is_sense_line(trimmed) {
+            let sense = trimmed.strip_prefix("# ").or_else(|| trimmed.strip_prefix('#')).unwrap_or(trimmed);
+            let plain = to_plain_text(sense);
+            if !plain.is_empty() {
+                definitions.push(plain);
+            }
+        }
+    }
+
+    definitions
+}
+
+/// True for a genuine `==Heading==` line, as opposed to a `===Subheading===`
+/// or deeper one.
+fn is_level2_heading(line: &str) -> bool {
+    line.starts_with("==") && !line.starts_with("===") && line.ends_with("==") && !line.ends_with("===")
+}
+
+/// True for a top-level `# sense` line, excluding `#:` quotation, `#*`
+/// citation, and `##` nested sub-bullets.
+fn is_sense_line(line: &str) -> bool {
+    line.starts_with('#') && !line.starts_with("#:") && !line.starts_with("#*") && !line.starts_with("##")
+}
+
+/// Strips `{{template|args}}`, `[[link|text]]`/`[[text]]`, and
+/// `''italic''`/`'''bold'''` wiki markup down to readable prose.
+fn to_plain_text(wikitext: &str) -> String {
+    let without_templates = strip_delimited(wikitext, "{{", "}}");
+    let without_links = strip_links(&without_templates);
+    let without_emphasis = strip_emphasis(&without_links);
+    without_emphasis.trim().to_string()
+}
+
+/// Percent-encodes a query-string component so `&`, `#`, and other
+/// reserved characters in `word` can't be misread as argument separators
+/// or a URL fragment by the Wiktionary API endpoint.
+fn percent_encode(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                result.push(byte as char)
+            }
+            _ => result.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    result
+}
+
+fn strip_delimited(text: &str, open: &str, close: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(open) {
+        result.push_str(&rest[..start]);
+        match rest[start..].find(close) {
+            Some(end) => rest = &rest[start + end + close.len()..],
+            None => return result,
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Strips `'''bold'''` and `''italic''` emphasis markers. Triple quotes
+/// are removed before double quotes so a run of five quotes (`'''''`)
+/// collapses correctly instead of leaving a stray `'`.
+fn strip_emphasis(text: &str) -> String {
+    text.replace("'''", "").replace("''", "")
+}
+
+fn strip_links(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("[[") {
+        result.push_str(&rest[..start]);
+        match rest[start..].find("]]") {
+            Some(end) => {
+                let inner = &rest[start + 2..start + end];
+                let display = inner.rsplit('|').next().unwrap_or(inner);
+                result.push_str(display);
+                rest = &rest[start + end + 2..];
+            }
+            None => return result,
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A realistic excerpt shaped like an actual Wiktionary page: the
+    /// English section nests its senses under a `===Noun===` and
+    /// `===Verb===` subheading before the next language section begins.
+    const MOUSE_PAGE: &str = r#"
+==English==
+
+===Noun===
+# A small [[rodent]].
+#: ''The '''mouse''' ran across the floor.''
+# A [[timid]] person.
+#* 1999, some book title:
+#*: ''He was a total mouse.''
+
+===Verb===
+# To move a [[computer]] mouse.
+
+==French==
+
+===Noun===
+# {{lb|fr}} A mouse (rodent).
+"#;
+
+    #[test]
+    fn parses_senses_nested_under_subheadings() {
+        let definitions = parse_english_definitions(MOUSE_PAGE);
+        assert_eq!(
+            definitions,
+            vec![
+                "A small rodent.",
+                "A timid person.",
+                "To move a computer mouse.",
+            ]
+        );
+    }
+
+    #[test]
+    fn skips_quotation_and_citation_sub_bullets() {
+        let definitions = parse_english_definitions(MOUSE_PAGE);
+        assert!(!definitions.iter().any(|defn| defn.contains("ran across")));
+        assert!(!definitions.iter().any(|defn| defn.contains("total mouse")));
+    }
+
+    #[test]
+    fn strips_emphasis_markup() {
+        let plain = to_plain_text("a '''bold''' and ''italic'' word");
+        assert_eq!(plain, "a bold and italic word");
+    }
+
+    #[test]
+    fn percent_encodes_reserved_query_characters() {
+        assert_eq!(percent_encode("rock&roll"), "rock%26roll");
+        assert_eq!(percent_encode("a#b"), "a%23b");
+        assert_eq!(percent_encode("mouse"), "mouse");
+    }
+}